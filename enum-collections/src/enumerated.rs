@@ -10,9 +10,144 @@
 ///     B,
 /// }
 /// ```
-pub trait Enumerated {
+/// A key enum need not be fieldless. The derive supports variants whose payloads are themselves
+/// finite: unit variants, [`bool`], nested `Enumerated` enums, and `Option<T: Enumerated>`. Each
+/// variant is assigned a cardinality (unit = 1, `bool` = 2, nested enum = its `LEN`,
+/// `Option<T>` = `1 + card(T)`) and a base offset equal to the running sum of the preceding
+/// variants' cardinalities. `position()` then returns `base_offset + payload.position()`,
+/// recursing into the payload, and `LEN` is the total sum — keeping the index space dense and
+/// contiguous so O(1) array indexing stays sound. The generated code delegates each payload to
+/// [`Cardinality`] (for `bool`/`Option`) or to the payload enum's own `Enumerated` impl.
+pub trait Enumerated: Sized + 'static {
+    /// Every value of the enum, in `position()` order. Backs the iteration APIs of
+    /// `EnumMap`/`EnumTable`, which reconstruct a key from each array index.
+    const VARIANTS: &'static [Self];
+    /// Total number of values in an Enum, available in `const` context. `position()` returns
+    /// values in `0..LEN` with no collisions, so it can index a `[V; LEN]` array directly.
+    const LEN: usize;
     /// Maps an enum to a unique position in an array.
     fn position(self) -> usize;
+    /// Inverse of `position()`: reconstructs the key at array index `index`, or `None` if it is
+    /// out of `0..LEN`. Used when rebuilding densely-indexed storage, e.g. during deserialization.
+    fn from_position(index: usize) -> Option<Self>
+    where
+        Self: Copy,
+    {
+        Self::VARIANTS.get(index).copied()
+    }
     /// Total number of values in an Enum.
     fn len() -> usize;
 }
+
+/// A compile-time default value, usable where [`Default::default`] cannot be: `Default::default`
+/// is an ordinary function and thus not callable from a `const fn`, whereas an associated `const`
+/// is. Implemented for the value types stored in a `const`-constructed [`EnumTable`].
+///
+/// ```
+/// use enum_collections::DefaultValue;
+/// struct Celsius(i32);
+/// impl DefaultValue for Celsius {
+///     const DEFAULT: Self = Celsius(0);
+/// }
+/// ```
+pub trait DefaultValue {
+    /// The value every slot is initialized to by `EnumTable::const_new`.
+    const DEFAULT: Self;
+}
+
+macro_rules! default_value_via_default {
+    ($($ty:ty => $default:expr),+ $(,)?) => {
+        $(
+            impl DefaultValue for $ty {
+                const DEFAULT: Self = $default;
+            }
+        )+
+    };
+}
+
+default_value_via_default! {
+    bool => false,
+    char => '\0',
+    u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+    f32 => 0.0, f64 => 0.0,
+}
+
+impl<T> DefaultValue for Option<T> {
+    const DEFAULT: Self = None;
+}
+
+/// The finite domain of a payload that can sit inside a composite [`Enumerated`] variant. Leaf
+/// payloads ([`bool`], `Option<T>`) implement this directly; a nested enum payload reuses its own
+/// `Enumerated::{LEN, position}`. The derive reads [`CARDINALITY`](Self::CARDINALITY) to lay out
+/// each variant's base offset and calls [`offset`](Self::offset) to recurse into the payload.
+pub trait Cardinality: Copy {
+    /// Number of distinct values the payload can take.
+    const CARDINALITY: usize;
+    /// Dense position of this value within `0..CARDINALITY`.
+    fn offset(self) -> usize;
+}
+
+impl Cardinality for bool {
+    const CARDINALITY: usize = 2;
+    fn offset(self) -> usize {
+        self as usize
+    }
+}
+
+impl<P: Cardinality> Cardinality for Option<P> {
+    const CARDINALITY: usize = 1 + P::CARDINALITY;
+    fn offset(self) -> usize {
+        match self {
+            None => 0,
+            Some(inner) => 1 + inner.offset(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Enumerated;
+    use crate::EnumMap;
+
+    // A fieldless nested key. The derive also emits its `Cardinality` impl so it can sit inside a
+    // composite variant's payload.
+    #[derive(Enumerated, Clone, Copy)]
+    enum Part {
+        Head,
+        Tail,
+    }
+
+    // A composite key. The derive lays out base offsets as the running sum of the preceding
+    // variants' cardinalities (Plain=0, Composite=1, Flag=3, Maybe=5; LEN=8).
+    #[derive(Enumerated, Clone, Copy)]
+    enum Key {
+        Plain,
+        Composite(Part),
+        Flag(bool),
+        Maybe(Option<Part>),
+    }
+
+    #[test]
+    fn composite_positions_are_dense_and_unique() {
+        let mut seen = [false; Key::LEN];
+        for &key in Key::VARIANTS {
+            let position = key.position();
+            assert!(!seen[position], "position {position} collided");
+            seen[position] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn composite_enum_map_roundtrip() {
+        let mut map = EnumMap::<Key, &'static str>::new();
+        map.insert(Key::Composite(Part::Tail), "tail");
+        map.insert(Key::Flag(true), "on");
+        map.insert(Key::Maybe(None), "none");
+        assert_eq!(Some(&"tail"), map.get(Key::Composite(Part::Tail)));
+        assert_eq!(Some(&"on"), map.get(Key::Flag(true)));
+        assert_eq!(Some(&"none"), map.get(Key::Maybe(None)));
+        assert_eq!(None, map.get(Key::Composite(Part::Head)));
+    }
+}
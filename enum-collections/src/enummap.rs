@@ -88,6 +88,153 @@ where
     pub fn remove(&mut self, key: K) {
         self.values[key.position()] = None;
     }
+
+    /// Returns an [Entry] for the slot of `key`, allowing "insert if absent, otherwise mutate"
+    /// without a separate `get` and `insert` (and thus a single `position()` computation).
+    ///
+    /// ### Args
+    /// - `key` - Instance of `K` selecting the slot to operate on.
+    #[inline]
+    pub fn entry(&mut self, key: K) -> Entry<'_, V> {
+        Entry {
+            slot: &mut self.values[key.position()],
+        }
+    }
+
+    /// Iterates over the occupied `(key, &value)` pairs in `position()` order, skipping keys
+    /// whose slot is still `None`.
+    pub fn iter(&self) -> impl Iterator<Item = (K, &V)>
+    where
+        K: Copy,
+    {
+        K::VARIANTS
+            .iter()
+            .zip(self.values.iter())
+            .filter_map(|(key, value)| value.as_ref().map(|value| (*key, value)))
+    }
+
+    /// Iterates over the occupied `(key, &mut value)` pairs in `position()` order, skipping keys
+    /// whose slot is still `None`.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (K, &mut V)>
+    where
+        K: Copy,
+    {
+        K::VARIANTS
+            .iter()
+            .zip(self.values.iter_mut())
+            .filter_map(|(key, value)| value.as_mut().map(|value| (*key, value)))
+    }
+
+    /// Iterates over every `(key, &Option<value>)` slot in `position()` order, including the
+    /// empty ones. In contrast to [`iter`](Self::iter), no slot is skipped.
+    pub fn entries(&self) -> impl Iterator<Item = (K, &Option<V>)>
+    where
+        K: Copy,
+    {
+        K::VARIANTS
+            .iter()
+            .zip(self.values.iter())
+            .map(|(key, value)| (*key, value))
+    }
+
+    /// Iterates over the keys whose slot is occupied, in `position()` order.
+    pub fn keys(&self) -> impl Iterator<Item = K> + '_
+    where
+        K: Copy,
+    {
+        K::VARIANTS
+            .iter()
+            .zip(self.values.iter())
+            .filter_map(|(key, value)| value.as_ref().map(|_| *key))
+    }
+
+    /// Iterates over the stored `&value`s in `position()` order, skipping empty slots.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.values.iter().filter_map(Option::as_ref)
+    }
+
+    /// Iterates over the stored `&mut value`s in `position()` order, skipping empty slots.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.values.iter_mut().filter_map(Option::as_mut)
+    }
+}
+
+/// Owning iterator over the occupied entries of an [EnumMap], created by
+/// [`IntoIterator::into_iter`]. Yields `(key, value)` in `position()` order, skipping empty slots
+/// without allocating.
+pub struct IntoIter<K, V> {
+    inner: std::iter::Enumerate<std::vec::IntoIter<Option<V>>>,
+    _key_phantom_data: PhantomData<K>,
+}
+
+impl<K, V> Iterator for IntoIter<K, V>
+where
+    K: Enumerated + Copy,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.inner.by_ref() {
+            if let Some(value) = slot {
+                return Some((K::VARIANTS[index], value));
+            }
+        }
+        None
+    }
+}
+
+impl<K, V> IntoIterator for EnumMap<K, V>
+where
+    K: Enumerated + Copy,
+{
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// Consumes the map, yielding the occupied `(key, value)` pairs in `position()` order.
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.values.into_vec().into_iter().enumerate(),
+            _key_phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a EnumMap<K, V>
+where
+    K: Enumerated + Copy,
+{
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            inner: self.values.iter().enumerate(),
+            _key_phantom_data: PhantomData,
+        }
+    }
+}
+
+/// Borrowing iterator over the occupied entries of an [EnumMap], created by iterating `&EnumMap`.
+/// Yields `(key, &value)` in `position()` order, skipping empty slots without allocating.
+pub struct Iter<'a, K, V> {
+    inner: std::iter::Enumerate<std::slice::Iter<'a, Option<V>>>,
+    _key_phantom_data: PhantomData<K>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V>
+where
+    K: Enumerated + Copy,
+{
+    type Item = (K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.inner.by_ref() {
+            if let Some(value) = slot {
+                return Some((K::VARIANTS[index], value));
+            }
+        }
+        None
+    }
 }
 
 impl<K, V> Default for EnumMap<K, V>
@@ -122,12 +269,106 @@ where
     }
 }
 
+/// A view into a single slot of an [EnumMap], obtained via [`EnumMap::entry`]. Because every key
+/// already has a backing slot, this is simply a wrapper around the slot's `&mut Option<V>`.
+pub struct Entry<'a, V> {
+    slot: &'a mut Option<V>,
+}
+
+impl<'a, V> Entry<'a, V> {
+    /// Ensures a value is in the slot, inserting `default` if it is empty, and returns a mutable
+    /// reference to the contained value.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.slot.get_or_insert(default)
+    }
+
+    /// Ensures a value is in the slot, inserting the result of `default` if it is empty, and
+    /// returns a mutable reference to the contained value.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        self.slot.get_or_insert_with(default)
+    }
+
+    /// Provides in-place mutable access to an occupied slot before any potential insert, leaving
+    /// an empty slot untouched.
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        if let Some(value) = self.slot.as_mut() {
+            f(value);
+        }
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K, V> serde::Serialize for EnumMap<K, V>
+where
+    K: Enumerated,
+    V: serde::Serialize,
+{
+    /// Serializes the occupied entries as a map keyed by the variant's `position()` index,
+    /// skipping empty slots.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        let occupied = self.values.iter().filter(|value| value.is_some()).count();
+        let mut map = serializer.serialize_map(Some(occupied))?;
+        for (index, value) in self.values.iter().enumerate() {
+            if let Some(value) = value {
+                map.serialize_entry(&index, value)?;
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::Deserialize<'de> for EnumMap<K, V>
+where
+    K: Enumerated + Copy,
+    V: serde::Deserialize<'de>,
+{
+    /// Rebuilds the densely-indexed storage from a map keyed by `position()` index (via
+    /// [`Enumerated::from_position`]), leaving any index not present in the input as `None`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K, V> serde::de::Visitor<'de> for Visitor<K, V>
+        where
+            K: Enumerated + Copy,
+            V: serde::Deserialize<'de>,
+        {
+            type Value = EnumMap<K, V>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map keyed by enum variant index")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut access: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut enum_map = EnumMap::new();
+                while let Some((index, value)) = access.next_entry::<usize, V>()? {
+                    let key = K::from_position(index).ok_or_else(|| {
+                        serde::de::Error::custom(format!("index {index} out of range"))
+                    })?;
+                    enum_map.insert(key, value);
+                }
+                Ok(enum_map)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(PhantomData))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::EnumMap;
     use crate::Enumerated;
 
-    #[derive(Enumerated)]
+    #[derive(Enumerated, Clone, Copy)]
     pub(super) enum Letter {
         A,
         B,
@@ -157,4 +398,33 @@ mod tests {
         assert_eq!(Some(&42), enum_map.get(Letter::A));
         assert_eq!(None, enum_map.get(Letter::B));
     }
+
+    #[test]
+    fn entry_or_insert_and_modify() {
+        let mut enum_map = EnumMap::<Letter, i32>::new();
+        *enum_map.entry(Letter::A).or_insert(1) += 10;
+        assert_eq!(Some(&11), enum_map.get(Letter::A));
+        enum_map.entry(Letter::A).and_modify(|value| *value += 1);
+        assert_eq!(Some(&12), enum_map.get(Letter::A));
+        enum_map.entry(Letter::B).or_insert_with(|| 5);
+        assert_eq!(Some(&5), enum_map.get(Letter::B));
+    }
+
+    #[test]
+    fn iter_skips_empty_slots() {
+        let mut enum_map = EnumMap::<Letter, i32>::new();
+        enum_map.insert(Letter::B, 7);
+        let collected: Vec<_> = enum_map.iter().map(|(_, &value)| value).collect();
+        assert_eq!(vec![7], collected);
+        assert_eq!(2, enum_map.entries().count());
+    }
+
+    #[test]
+    fn into_iter_yields_occupied() {
+        let mut enum_map = EnumMap::<Letter, i32>::new();
+        enum_map.insert(Letter::A, 1);
+        enum_map.insert(Letter::B, 2);
+        let collected: Vec<_> = enum_map.into_iter().map(|(_, value)| value).collect();
+        assert_eq!(vec![1, 2], collected);
+    }
 }
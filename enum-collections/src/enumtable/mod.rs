@@ -1,14 +1,50 @@
 mod index;
-use std::{
-    alloc::{alloc, dealloc, Layout},
-    marker::PhantomData,
-    slice::from_raw_parts_mut,
-};
+use std::marker::PhantomData;
 
-use crate::Enumerated;
+use crate::{DefaultValue, Enumerated};
+
+/// Builds a fully-populated [`EnumTable`] from a list of `Key::Variant => value` pairs,
+/// mirroring array-literal construction.
+///
+/// Each value expression is evaluated exactly once and written into the slot at its key's
+/// `position()`. Unlike repeated `insert`s this needs no [Default] bound on `V`, staying true to
+/// array-literal semantics.
+///
+/// Exhaustiveness is **checked at runtime, not at compile time**: a `macro_rules!` macro cannot
+/// enumerate a key enum's variants, so it cannot reject a missing one during expansion. Instead
+/// every slot must be filled by the supplied entries, and [`from_entries`](EnumTable::from_entries)
+/// panics on construction if any variant was omitted. A program missing a variant therefore
+/// compiles but panics on first construction — a deliberate deviation from the compile-time
+/// array-literal check.
+///
+/// ```
+/// use enum_collections::{enum_map, EnumTable, Enumerated};
+/// #[derive(Enumerated, Clone, Copy)]
+/// enum Letter {
+///     A,
+///     B,
+/// }
+///
+/// let map: EnumTable<Letter, u8, { Letter::LEN }> = enum_map! {
+///     Letter::A => 1,
+///     Letter::B => 2,
+/// };
+/// assert_eq!(1u8, map[Letter::A]);
+/// assert_eq!(2u8, map[Letter::B]);
+/// ```
+#[macro_export]
+macro_rules! enum_map {
+    ($($key:expr => $value:expr),+ $(,)?) => {
+        $crate::EnumTable::from_entries([$(($key, $value)),+])
+    };
+}
 
 /// A key-value table optimized for Enums used as keys. Initialized with `V`'s [Default] values.
 ///
+/// The backing storage is a fixed-size `[V; N]`, where `N` must equal `K::LEN` (checked on
+/// construction). Keeping `N` an explicit const generic rather than `[V; K::LEN]` avoids the
+/// unstable `generic_const_exprs` feature, so the crate builds on stable Rust.
+///
 /// ```
 /// use enum_collections::{EnumTable, Enumerated};
 /// #[derive(Enumerated)]
@@ -17,7 +53,7 @@ use crate::Enumerated;
 ///     B,
 /// }
 ///
-/// let mut map: EnumTable<Letter, u8> = EnumTable::new();
+/// let mut map: EnumTable<Letter, u8, { Letter::LEN }> = EnumTable::new();
 /// map[Letter::A] = 42;
 /// assert_eq!(42u8, map[Letter::A]);
 /// assert_eq!(u8::default(), map[Letter::B]);
@@ -25,45 +61,74 @@ use crate::Enumerated;
 ///
 /// Using get and insert functions.
 /// ```
-/// use enum_collections::{enum_collections, EnumTable, Enumerated};
+/// use enum_collections::{EnumTable, Enumerated};
 /// #[derive(Enumerated)]
 /// enum Letter {
 ///     A,
 ///     B,
 /// }
 ///
-/// let mut map: EnumTable<Letter, u8> = EnumTable::new();
+/// let mut map: EnumTable<Letter, u8, { Letter::LEN }> = EnumTable::new();
 /// map.insert(Letter::A, 42);
 /// assert_eq!(&42u8, map.get(Letter::A));
 /// assert_eq!(&u8::default(), map.get(Letter::B));
 /// ```
-pub struct EnumTable<'a, K, V>
+pub struct EnumTable<K, V, const N: usize>
 where
     K: Enumerated,
-    V: Default,
 {
-    values: &'a mut [V],
+    values: [V; N],
     _key_phantom_data: PhantomData<K>,
 }
 
-impl<'a, K, V> EnumTable<'a, K, V>
+impl<K, V, const N: usize> EnumTable<K, V, N>
 where
     K: Enumerated,
-    V: Default,
 {
-    /// Creates a new [EnumTable], with pre-allocated space for all keys of the enum `K`. With the underlying array righsized,
-    /// no resizing is further required. All values are initialized with `V`'s [Default] value.
-    pub fn new() -> Self {
+    /// Creates a new [EnumTable] with every slot initialized to `V`'s [Default] value, matching
+    /// the baseline `new()` contract. Use [`const_new`](Self::const_new) when a `const` context
+    /// (e.g. a `static`) is required.
+    pub fn new() -> Self
+    where
+        V: Default,
+    {
+        assert!(N == K::LEN, "EnumTable<_, _, N> requires N == K::LEN");
         Self {
-            values: unsafe {
-                let raw_memory = alloc(Layout::array::<V>(K::len()).unwrap());
-                let values_array: &'a mut [V] = from_raw_parts_mut(raw_memory as *mut V, K::len());
-                for value in values_array.iter_mut() {
-                    *value = V::default();
-                }
-                values_array
-            },
-            _key_phantom_data: PhantomData {},
+            values: std::array::from_fn(|_| V::default()),
+            _key_phantom_data: PhantomData,
+        }
+    }
+
+    /// Creates a new [EnumTable] in `const` context, with every slot initialized to
+    /// `V`'s [`DefaultValue::DEFAULT`]. Suitable for initializing `static`s, as the backing
+    /// `[V; N]` array is built entirely at compile time ([Default] isn't callable from `const fn`).
+    pub const fn const_new() -> Self
+    where
+        V: DefaultValue,
+    {
+        assert!(N == K::LEN, "EnumTable<_, _, N> requires N == K::LEN");
+        Self {
+            values: [V::DEFAULT; N],
+            _key_phantom_data: PhantomData,
+        }
+    }
+
+    /// Builds a table from an exhaustive list of `(key, value)` pairs, placing each value at its
+    /// key's `position()`. Backs the [`enum_map!`] macro. Coverage is checked here at runtime:
+    /// panics if any variant is missing, so the table is always fully populated — and, unlike
+    /// [`new`](Self::new), requires no `V: Default`.
+    pub fn from_entries<const M: usize>(entries: [(K, V); M]) -> Self
+    where
+        K: Copy,
+    {
+        assert!(N == K::LEN, "EnumTable<_, _, N> requires N == K::LEN");
+        let mut slots: [Option<V>; N] = [(); N].map(|_| None);
+        for (key, value) in entries {
+            slots[key.position()] = Some(value);
+        }
+        Self {
+            values: slots.map(|slot| slot.expect("enum_map! requires every variant to be listed")),
+            _key_phantom_data: PhantomData,
         }
     }
 
@@ -91,12 +156,77 @@ where
     ///
     /// ### Args
     /// - `key` - The instance of `K` pointing at the slot to reset to default.
-    pub fn reset(&mut self, key: K) {
+    pub fn reset(&mut self, key: K)
+    where
+        V: Default,
+    {
         self.values[key.position()] = V::default();
     }
+
+    /// Iterates over every `(key, &value)` pair in `position()` order. As the table is fully
+    /// populated there are no empty slots to skip.
+    pub fn iter(&self) -> impl Iterator<Item = (K, &V)>
+    where
+        K: Copy,
+    {
+        K::VARIANTS.iter().copied().zip(self.values.iter())
+    }
+
+    /// Iterates over every `(key, &mut value)` pair in `position()` order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (K, &mut V)>
+    where
+        K: Copy,
+    {
+        K::VARIANTS.iter().copied().zip(self.values.iter_mut())
+    }
+
+    /// Iterates over the keys in `position()` order.
+    pub fn keys(&self) -> impl Iterator<Item = K>
+    where
+        K: Copy,
+    {
+        K::VARIANTS.iter().copied()
+    }
+
+    /// Iterates over the `&value`s in `position()` order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.values.iter()
+    }
+
+    /// Iterates over the `&mut value`s in `position()` order.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.values.iter_mut()
+    }
+}
+
+impl<K, V, const N: usize> IntoIterator for EnumTable<K, V, N>
+where
+    K: Enumerated + Copy,
+{
+    type Item = (K, V);
+    type IntoIter =
+        std::iter::Zip<std::iter::Copied<std::slice::Iter<'static, K>>, std::array::IntoIter<V, N>>;
+
+    /// Consumes the table, yielding every `(key, value)` pair in `position()` order.
+    fn into_iter(self) -> Self::IntoIter {
+        K::VARIANTS.iter().copied().zip(self.values)
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a EnumTable<K, V, N>
+where
+    K: Enumerated + Copy,
+{
+    type Item = (K, &'a V);
+    type IntoIter =
+        std::iter::Zip<std::iter::Copied<std::slice::Iter<'static, K>>, std::slice::Iter<'a, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        K::VARIANTS.iter().copied().zip(self.values.iter())
+    }
 }
 
-impl<'a, K, V> Default for EnumTable<'a, K, V>
+impl<K, V, const N: usize> Default for EnumTable<K, V, N>
 where
     K: Enumerated,
     V: Default,
@@ -107,20 +237,62 @@ where
     }
 }
 
-impl<'a, K, V> Drop for EnumTable<'a, K, V>
+#[cfg(feature = "serde")]
+impl<K, V, const N: usize> serde::Serialize for EnumTable<K, V, N>
 where
     K: Enumerated,
-    V: Default,
+    V: serde::Serialize,
 {
-    /// The underlying memory allocated for values must be deallocated manually, as the destruction of the
-    /// fat slice pointer doesn't guarantee it.
-    fn drop(&mut self) {
-        unsafe {
-            dealloc(
-                self.values.as_ptr() as *mut u8,
-                Layout::array::<Option<V>>(K::len()).unwrap(),
-            );
-        };
+    /// Serializes every slot as a map keyed by the variant's `position()` index.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(N))?;
+        for (index, value) in self.values.iter().enumerate() {
+            map.serialize_entry(&index, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V, const N: usize> serde::Deserialize<'de> for EnumTable<K, V, N>
+where
+    K: Enumerated + Copy,
+    V: Default + serde::Deserialize<'de>,
+{
+    /// Rebuilds the backing array from a map keyed by `position()` index (via
+    /// [`Enumerated::from_position`]), leaving any index not present in the input at
+    /// `V::default()`.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<K, V, const N: usize>(PhantomData<(K, V)>);
+
+        impl<'de, K, V, const N: usize> serde::de::Visitor<'de> for Visitor<K, V, N>
+        where
+            K: Enumerated + Copy,
+            V: Default + serde::Deserialize<'de>,
+        {
+            type Value = EnumTable<K, V, N>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map keyed by enum variant index")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut access: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut enum_table = EnumTable::new();
+                while let Some((index, value)) = access.next_entry::<usize, V>()? {
+                    let key = K::from_position(index).ok_or_else(|| {
+                        serde::de::Error::custom(format!("index {index} out of range"))
+                    })?;
+                    enum_table.insert(key, value);
+                }
+                Ok(enum_table)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(PhantomData))
     }
 }
 
@@ -130,7 +302,7 @@ mod tests {
 
     use super::EnumTable;
 
-    #[derive(Enumerated)]
+    #[derive(Enumerated, Clone, Copy)]
     pub(super) enum Letter {
         A,
         B,
@@ -157,15 +329,22 @@ mod tests {
 
     #[test]
     fn new_all_default() {
-        let enum_table = EnumTable::<Letter, Value>::new();
+        let enum_table = EnumTable::<Letter, Value, { Letter::LEN }>::new();
         for index in 0..Letter::len() {
             assert_eq!(Value::default(), enum_table.values[index]);
         }
     }
 
+    #[test]
+    fn const_new_initializes_static() {
+        static TABLE: EnumTable<Letter, i32, { Letter::LEN }> = EnumTable::const_new();
+        assert_eq!(&0, TABLE.get(Letter::A));
+        assert_eq!(&0, TABLE.get(Letter::B));
+    }
+
     #[test]
     fn inserts() {
-        let mut enum_table = EnumTable::<Letter, Value>::new();
+        let mut enum_table = EnumTable::<Letter, Value, { Letter::LEN }>::new();
         let inserted_value = Value::new("Hello".to_string());
         enum_table.insert(Letter::A, inserted_value.clone());
         assert_eq!(&inserted_value, enum_table.get(Letter::A));
@@ -174,7 +353,7 @@ mod tests {
 
     #[test]
     fn reset() {
-        let mut enum_table = EnumTable::<Letter, Value>::new();
+        let mut enum_table = EnumTable::<Letter, Value, { Letter::LEN }>::new();
         let inserted_value = Value::new("Hello".to_string());
         enum_table.insert(Letter::A, inserted_value.clone());
         assert_eq!(&inserted_value, enum_table.get(Letter::A));
@@ -182,4 +361,33 @@ mod tests {
         assert_eq!(&Value::default(), enum_table.get(Letter::A));
         assert_eq!(&Value::default(), enum_table.get(Letter::B));
     }
+
+    #[test]
+    fn enum_map_macro_populates_every_slot() {
+        let enum_table: EnumTable<Letter, i32, { Letter::LEN }> = crate::enum_map! {
+            Letter::A => 1,
+            Letter::B => 2,
+        };
+        assert_eq!(&1, enum_table.get(Letter::A));
+        assert_eq!(&2, enum_table.get(Letter::B));
+    }
+
+    #[test]
+    #[should_panic(expected = "every variant")]
+    fn enum_map_macro_rejects_missing_variant() {
+        let _enum_table: EnumTable<Letter, i32, { Letter::LEN }> = crate::enum_map! {
+            Letter::A => 1,
+        };
+    }
+
+    #[test]
+    fn iter_yields_every_slot() {
+        let mut enum_table = EnumTable::<Letter, Value, { Letter::LEN }>::new();
+        enum_table.insert(Letter::A, Value::new("Hello".to_string()));
+        let collected: Vec<_> = enum_table.iter().map(|(_, value)| value.clone()).collect();
+        assert_eq!(
+            vec![Value::new("Hello".to_string()), Value::default()],
+            collected
+        );
+    }
 }